@@ -0,0 +1,180 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use pyo3::create_exception;
+use pyo3::prelude::*;
+
+create_exception!(
+    fromager._lib.build_order,
+    BuildCycleError,
+    pyo3::exceptions::PyException
+);
+
+/// Compute dependency "layers": each inner list is buildable in parallel once
+/// every prior layer has finished, via Kahn's algorithm over an adjacency map
+/// with in-degree counts.
+///
+/// `edges` are `(dependency, dependent)` pairs, i.e. `dependent` requires
+/// `dependency` to be built first. Raises `BuildCycleError` carrying the
+/// offending strongly-connected components if the graph isn't a DAG.
+#[pyfunction]
+pub fn topological_build_order(
+    py: Python<'_>,
+    edges: Vec<(String, String)>,
+) -> PyResult<Vec<Vec<String>>> {
+    py.allow_threads(|| {
+        let mut nodes: HashSet<String> = HashSet::new();
+        let mut adjacency: HashMap<String, Vec<String>> = HashMap::new();
+        let mut in_degree: HashMap<String, usize> = HashMap::new();
+
+        for (dependency, dependent) in &edges {
+            nodes.insert(dependency.clone());
+            nodes.insert(dependent.clone());
+            adjacency
+                .entry(dependency.clone())
+                .or_default()
+                .push(dependent.clone());
+            *in_degree.entry(dependent.clone()).or_insert(0) += 1;
+            in_degree.entry(dependency.clone()).or_insert(0);
+        }
+
+        let mut remaining_degree = in_degree.clone();
+        let mut layers = Vec::new();
+        let mut frontier: Vec<String> = remaining_degree
+            .iter()
+            .filter(|(_, degree)| **degree == 0)
+            .map(|(node, _)| node.clone())
+            .collect();
+        frontier.sort();
+
+        let mut visited = 0usize;
+        let mut queue: VecDeque<String> = frontier.into_iter().collect();
+        while !queue.is_empty() {
+            let mut layer: Vec<String> = queue.drain(..).collect();
+            layer.sort();
+            visited += layer.len();
+
+            let mut next_frontier = Vec::new();
+            for node in &layer {
+                if let Some(successors) = adjacency.get(node) {
+                    for successor in successors {
+                        let degree = remaining_degree.get_mut(successor).unwrap();
+                        *degree -= 1;
+                        if *degree == 0 {
+                            next_frontier.push(successor.clone());
+                        }
+                    }
+                }
+            }
+            layers.push(layer);
+            queue = next_frontier.into_iter().collect();
+        }
+
+        if visited != nodes.len() {
+            let sccs = strongly_connected_components(&nodes, &adjacency);
+            // A cycle is either a multi-node SCC, or a single-node SCC that
+            // has a self-loop (e.g. edge `(a, a)`) — both are unbuildable.
+            let cycles: Vec<Vec<String>> = sccs
+                .into_iter()
+                .filter(|scc| {
+                    scc.len() > 1
+                        || scc.first().is_some_and(|node| {
+                            adjacency
+                                .get(node)
+                                .is_some_and(|successors| successors.iter().any(|s| s == node))
+                        })
+                })
+                .collect();
+            let message = format!(
+                "build graph contains a cycle; offending package group(s): {cycles:?}"
+            );
+            return Err(BuildCycleError::new_err((message, cycles)));
+        }
+
+        Ok(layers)
+    })
+}
+
+/// Tarjan's strongly-connected-components algorithm. Genuinely iterative
+/// (an explicit work stack of `(node, next successor index)` frames stands
+/// in for the call stack) so it doesn't overflow on the tens-of-thousands-
+/// of-edges graphs this is meant to diagnose cycles in.
+fn strongly_connected_components(
+    nodes: &HashSet<String>,
+    adjacency: &HashMap<String, Vec<String>>,
+) -> Vec<Vec<String>> {
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut lowlink: HashMap<String, usize> = HashMap::new();
+    let mut on_stack: HashSet<String> = HashSet::new();
+    let mut stack: Vec<String> = Vec::new();
+    let mut counter = 0usize;
+    let mut sccs: Vec<Vec<String>> = Vec::new();
+
+    let mut sorted_nodes: Vec<&String> = nodes.iter().collect();
+    sorted_nodes.sort();
+
+    for start in sorted_nodes {
+        if index.contains_key(start) {
+            continue;
+        }
+
+        // Each frame is a node together with how many of its successors have
+        // already been visited; it replaces one level of call-stack recursion.
+        let mut work: Vec<(String, usize)> = vec![(start.clone(), 0)];
+        while let Some((node, child_idx)) = work.last().cloned() {
+            if child_idx == 0 {
+                index.insert(node.clone(), counter);
+                lowlink.insert(node.clone(), counter);
+                counter += 1;
+                stack.push(node.clone());
+                on_stack.insert(node.clone());
+            }
+
+            let successors = adjacency.get(&node).cloned().unwrap_or_default();
+            if child_idx < successors.len() {
+                work.last_mut().unwrap().1 += 1;
+                let successor = &successors[child_idx];
+                if !index.contains_key(successor) {
+                    work.push((successor.clone(), 0));
+                } else if on_stack.contains(successor) {
+                    let successor_index = index[successor];
+                    let node_low = lowlink[&node];
+                    lowlink.insert(node.clone(), node_low.min(successor_index));
+                }
+                continue;
+            }
+
+            work.pop();
+            if let Some((parent, _)) = work.last() {
+                let node_low = lowlink[&node];
+                let parent_low = lowlink[parent];
+                lowlink.insert(parent.clone(), parent_low.min(node_low));
+            }
+
+            if lowlink[&node] == index[&node] {
+                let mut component = Vec::new();
+                loop {
+                    let member = stack.pop().unwrap();
+                    on_stack.remove(&member);
+                    let is_node = member == node;
+                    component.push(member);
+                    if is_node {
+                        break;
+                    }
+                }
+                component.sort();
+                sccs.push(component);
+            }
+        }
+    }
+
+    sccs
+}
+
+pub fn register(py: Python<'_>, parent: &PyModule) -> PyResult<()> {
+    parent.add_function(wrap_pyfunction!(topological_build_order, parent)?)?;
+    parent.add(
+        "BuildCycleError",
+        py.get_type::<BuildCycleError>(),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+
+use blake2::{Blake2b512, Digest};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+use sha2::Sha256;
+
+const CHUNK_SIZE: usize = 1024 * 1024;
+
+enum Algorithm {
+    Sha256,
+    Blake2b,
+}
+
+impl Algorithm {
+    fn parse(name: &str) -> PyResult<Self> {
+        match name {
+            "sha256" => Ok(Algorithm::Sha256),
+            "blake2b" => Ok(Algorithm::Blake2b),
+            other => Err(PyValueError::new_err(format!(
+                "unsupported hash algorithm {other:?}, expected 'sha256' or 'blake2b'"
+            ))),
+        }
+    }
+
+    fn digest_file(&self, path: &str) -> Result<String, String> {
+        let mut file =
+            File::open(path).map_err(|err| format!("failed to open {path:?}: {err}"))?;
+        let mut buf = vec![0u8; CHUNK_SIZE];
+        match self {
+            Algorithm::Sha256 => {
+                let mut hasher = Sha256::new();
+                loop {
+                    let read = file
+                        .read(&mut buf)
+                        .map_err(|err| format!("failed to read {path:?}: {err}"))?;
+                    if read == 0 {
+                        break;
+                    }
+                    hasher.update(&buf[..read]);
+                }
+                Ok(hex::encode(hasher.finalize()))
+            }
+            Algorithm::Blake2b => {
+                let mut hasher = Blake2b512::new();
+                loop {
+                    let read = file
+                        .read(&mut buf)
+                        .map_err(|err| format!("failed to read {path:?}: {err}"))?;
+                    if read == 0 {
+                        break;
+                    }
+                    hasher.update(&buf[..read]);
+                }
+                Ok(hex::encode(hasher.finalize()))
+            }
+        }
+    }
+}
+
+/// Hash a batch of files in parallel and return hex digests keyed by path.
+///
+/// Files that can't be read produce an `"error: <message>"` entry rather than
+/// aborting the whole batch, since a single missing/unreadable file shouldn't
+/// stop us from reporting the digests we could compute.
+#[pyfunction]
+pub fn hash_files(py: Python<'_>, paths: Vec<String>, algorithm: &str) -> PyResult<HashMap<String, String>> {
+    let algorithm = Algorithm::parse(algorithm)?;
+
+    py.allow_threads(|| {
+        Ok(paths
+            .par_iter()
+            .map(|path| {
+                let digest = match algorithm.digest_file(path) {
+                    Ok(digest) => digest,
+                    Err(err) => format!("error: {err}"),
+                };
+                (path.clone(), digest)
+            })
+            .collect())
+    })
+}
+
+pub fn register(py: Python<'_>, parent: &PyModule) -> PyResult<()> {
+    let _ = py;
+    parent.add_function(wrap_pyfunction!(hash_files, parent)?)?;
+    Ok(())
+}
@@ -1,12 +1,51 @@
 use pyo3::prelude::*;
 
+mod build_order;
+mod hashing;
+mod markers;
+mod version;
+mod wheel;
+
 #[pyfunction]
 fn add(a: usize, b: usize) -> PyResult<usize> {
     Ok(a + b)
 }
 
+/// Register a child module as a submodule of `parent` and make it importable
+/// as `<parent's name>.<child's name>` (rather than only reachable as an
+/// attribute), so e.g. `from fromager._lib.version import Version` works.
+fn add_submodule(py: Python<'_>, parent: &PyModule, child: &PyModule) -> PyResult<()> {
+    parent.add_submodule(child)?;
+    let full_name = format!("{}.{}", parent.name()?, child.name()?);
+    py.import("sys")?
+        .getattr("modules")?
+        .set_item(full_name, child)?;
+    Ok(())
+}
+
 #[pymodule]
-fn _lib(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+fn _lib(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(add, m)?)?;
+
+    let version_mod = PyModule::new(py, "version")?;
+    version::register(py, version_mod)?;
+    add_submodule(py, m, version_mod)?;
+
+    let markers_mod = PyModule::new(py, "markers")?;
+    markers::register(py, markers_mod)?;
+    add_submodule(py, m, markers_mod)?;
+
+    let hashing_mod = PyModule::new(py, "hashing")?;
+    hashing::register(py, hashing_mod)?;
+    add_submodule(py, m, hashing_mod)?;
+
+    let wheel_mod = PyModule::new(py, "wheel")?;
+    wheel::register(py, wheel_mod)?;
+    add_submodule(py, m, wheel_mod)?;
+
+    let build_order_mod = PyModule::new(py, "build_order")?;
+    build_order::register(py, build_order_mod)?;
+    add_submodule(py, m, build_order_mod)?;
+
     Ok(())
 }
@@ -0,0 +1,351 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::version;
+
+/// A single comparison in a version specifier list, e.g. `>=1.0`.
+#[derive(Clone, Debug)]
+struct Specifier {
+    op: String,
+    version: String,
+}
+
+/// A node in a PEP 508 marker expression tree.
+#[derive(Clone, Debug)]
+enum MarkerNode {
+    Compare {
+        lhs: MarkerValue,
+        op: String,
+        rhs: MarkerValue,
+    },
+    And(Box<MarkerNode>, Box<MarkerNode>),
+    Or(Box<MarkerNode>, Box<MarkerNode>),
+}
+
+#[derive(Clone, Debug)]
+enum MarkerValue {
+    Variable(String),
+    Literal(String),
+}
+
+impl MarkerNode {
+    fn evaluate(&self, py: Python<'_>, env: &PyDict) -> PyResult<bool> {
+        match self {
+            MarkerNode::And(a, b) => Ok(a.evaluate(py, env)? && b.evaluate(py, env)?),
+            MarkerNode::Or(a, b) => Ok(a.evaluate(py, env)? || b.evaluate(py, env)?),
+            MarkerNode::Compare { lhs, op, rhs } => {
+                let lhs = lhs.resolve(env)?;
+                let rhs = rhs.resolve(env)?;
+                Ok(match op.as_str() {
+                    "==" => lhs == rhs,
+                    "!=" => lhs != rhs,
+                    ">=" | "<=" | ">" | "<" => {
+                        // Marker variables like `python_version` are version
+                        // strings, not opaque text — "3.9" must sort before
+                        // "3.10", which plain string comparison gets wrong.
+                        // Fall back to string ordering for non-version operands
+                        // (e.g. `platform_release`) so they still compare.
+                        let ordering = match (version::try_parse(&lhs), version::try_parse(&rhs)) {
+                            (Some(l), Some(r)) => l.cmp(&r),
+                            _ => lhs.cmp(&rhs),
+                        };
+                        match op.as_str() {
+                            ">=" => ordering != Ordering::Less,
+                            "<=" => ordering != Ordering::Greater,
+                            ">" => ordering == Ordering::Greater,
+                            "<" => ordering == Ordering::Less,
+                            _ => unreachable!(),
+                        }
+                    }
+                    "in" => rhs.contains(&lhs),
+                    "not in" => !rhs.contains(&lhs),
+                    other => {
+                        return Err(PyValueError::new_err(format!(
+                            "unsupported marker operator {other:?}"
+                        )))
+                    }
+                })
+            }
+        }
+    }
+}
+
+impl MarkerValue {
+    fn resolve(&self, env: &PyDict) -> PyResult<String> {
+        match self {
+            MarkerValue::Literal(s) => Ok(s.clone()),
+            MarkerValue::Variable(name) => match env.get_item(name)? {
+                Some(value) => value.extract::<String>(),
+                None => Ok(String::new()),
+            },
+        }
+    }
+}
+
+/// A tokenizer/parser for the small marker grammar: comparisons joined by
+/// `and`/`or` with parenthesization, operands are identifiers or quoted strings.
+struct MarkerParser<'a> {
+    tokens: Vec<&'a str>,
+    pos: usize,
+}
+
+fn tokenize_marker(text: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i] as char;
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '(' || c == ')' {
+            tokens.push(&text[i..i + 1]);
+            i += 1;
+        } else if c == '\'' || c == '"' {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i] as char != c {
+                i += 1;
+            }
+            i += 1;
+            tokens.push(&text[start..i]);
+        } else if "=<>!~".contains(c) {
+            // Comparison operators (`==`, `>=`, `!=`, ...) don't require
+            // surrounding whitespace, so tokenize them as their own run
+            // instead of folding into the identifier branch below.
+            let start = i;
+            while i < bytes.len() && "=<>!~".contains(bytes[i] as char) {
+                i += 1;
+            }
+            tokens.push(&text[start..i]);
+        } else {
+            let start = i;
+            while i < bytes.len() {
+                let c = bytes[i] as char;
+                if c.is_whitespace() || c == '(' || c == ')' || "=<>!~".contains(c) {
+                    break;
+                }
+                i += 1;
+            }
+            tokens.push(&text[start..i]);
+        }
+    }
+    tokens
+}
+
+impl<'a> MarkerParser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            tokens: tokenize_marker(text),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<&'a str> {
+        let tok = self.peek();
+        self.pos += 1;
+        tok
+    }
+
+    fn parse_expr(&mut self) -> PyResult<MarkerNode> {
+        let mut node = self.parse_and()?;
+        while self.peek() == Some("or") {
+            self.next();
+            let rhs = self.parse_and()?;
+            node = MarkerNode::Or(Box::new(node), Box::new(rhs));
+        }
+        Ok(node)
+    }
+
+    fn parse_and(&mut self) -> PyResult<MarkerNode> {
+        let mut node = self.parse_atom()?;
+        while self.peek() == Some("and") {
+            self.next();
+            let rhs = self.parse_atom()?;
+            node = MarkerNode::And(Box::new(node), Box::new(rhs));
+        }
+        Ok(node)
+    }
+
+    fn parse_atom(&mut self) -> PyResult<MarkerNode> {
+        if self.peek() == Some("(") {
+            self.next();
+            let node = self.parse_expr()?;
+            if self.next() != Some(")") {
+                return Err(PyValueError::new_err("unbalanced parentheses in marker"));
+            }
+            return Ok(node);
+        }
+        let lhs = self.parse_value()?;
+        let mut op = self
+            .next()
+            .ok_or_else(|| PyValueError::new_err("expected operator in marker"))?
+            .to_string();
+        if op == "not" && self.peek() == Some("in") {
+            self.next();
+            op = "not in".to_string();
+        }
+        let rhs = self.parse_value()?;
+        Ok(MarkerNode::Compare { lhs, op, rhs })
+    }
+
+    fn parse_value(&mut self) -> PyResult<MarkerValue> {
+        let tok = self
+            .next()
+            .ok_or_else(|| PyValueError::new_err("unexpected end of marker expression"))?;
+        if (tok.starts_with('\'') && tok.ends_with('\'') && tok.len() >= 2)
+            || (tok.starts_with('"') && tok.ends_with('"') && tok.len() >= 2)
+        {
+            Ok(MarkerValue::Literal(tok[1..tok.len() - 1].to_string()))
+        } else {
+            Ok(MarkerValue::Variable(tok.to_string()))
+        }
+    }
+}
+
+fn parse_marker(text: &str) -> PyResult<MarkerNode> {
+    let mut parser = MarkerParser::new(text);
+    let node = parser.parse_expr()?;
+    if parser.pos != parser.tokens.len() {
+        return Err(PyValueError::new_err(format!(
+            "trailing data in marker expression {text:?}"
+        )));
+    }
+    Ok(node)
+}
+
+fn parse_specifiers(text: &str) -> PyResult<Vec<Specifier>> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|part| {
+            let op_len = ["===", "~=", "==", "!=", ">=", "<=", ">", "<"]
+                .iter()
+                .find(|op| part.starts_with(**op))
+                .map(|op| op.len())
+                .ok_or_else(|| PyValueError::new_err(format!("invalid specifier {part:?}")))?;
+            Ok(Specifier {
+                op: part[..op_len].to_string(),
+                version: part[op_len..].trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// A parsed PEP 508 requirement string.
+#[pyclass(module = "fromager._lib.markers")]
+pub struct Requirement {
+    #[pyo3(get)]
+    name: String,
+    #[pyo3(get)]
+    extras: HashSet<String>,
+    #[pyo3(get)]
+    url: Option<String>,
+    specifiers: Vec<Specifier>,
+    marker: Option<MarkerNode>,
+    marker_text: Option<String>,
+}
+
+#[pymethods]
+impl Requirement {
+    #[getter]
+    fn specifier(&self) -> Vec<(String, String)> {
+        self.specifiers
+            .iter()
+            .map(|s| (s.op.clone(), s.version.clone()))
+            .collect()
+    }
+
+    #[getter]
+    fn marker(&self) -> Option<String> {
+        self.marker_text.clone()
+    }
+
+    fn evaluate(&self, py: Python<'_>, environment: &PyDict) -> PyResult<bool> {
+        match &self.marker {
+            Some(node) => node.evaluate(py, environment),
+            None => Ok(true),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Requirement({:?})", self.name)
+    }
+}
+
+/// Parse a PEP 508 requirement string into name, extras, specifiers, marker and URL.
+///
+/// Grammar: `name ["[" extra ("," extra)* "]"] [specifier-list] ["@" url] [";" marker]`.
+#[pyfunction]
+pub fn parse_requirement(text: &str) -> PyResult<Requirement> {
+    let text = text.trim();
+
+    let (body, marker_text) = match text.split_once(';') {
+        Some((body, marker)) => (body.trim(), Some(marker.trim().to_string())),
+        None => (text, None),
+    };
+
+    let (body, url) = match body.split_once('@') {
+        Some((body, url)) => (body.trim(), Some(url.trim().to_string())),
+        None => (body, None),
+    };
+
+    let name_end = body
+        .find(|c: char| c == '[' || c == '(' || "<>=!~ ".contains(c))
+        .unwrap_or(body.len());
+    let name = body[..name_end].trim().to_string();
+    if name.is_empty() {
+        return Err(PyValueError::new_err(format!(
+            "missing distribution name in requirement {text:?}"
+        )));
+    }
+    let mut rest = body[name_end..].trim();
+
+    let mut extras = HashSet::new();
+    if let Some(stripped) = rest.strip_prefix('[') {
+        let end = stripped
+            .find(']')
+            .ok_or_else(|| PyValueError::new_err(format!("unbalanced '[' in requirement {text:?}")))?;
+        for extra in stripped[..end].split(',') {
+            let extra = extra.trim();
+            if !extra.is_empty() {
+                extras.insert(extra.to_string());
+            }
+        }
+        rest = stripped[end + 1..].trim();
+    }
+
+    let specifiers = if rest.is_empty() {
+        Vec::new()
+    } else {
+        parse_specifiers(rest)?
+    };
+
+    let marker = match &marker_text {
+        Some(m) => Some(parse_marker(m)?),
+        None => None,
+    };
+
+    Ok(Requirement {
+        name,
+        extras,
+        url,
+        specifiers,
+        marker,
+        marker_text,
+    })
+}
+
+pub fn register(py: Python<'_>, parent: &PyModule) -> PyResult<()> {
+    let _ = py;
+    parent.add_class::<Requirement>()?;
+    parent.add_function(wrap_pyfunction!(parse_requirement, parent)?)?;
+    Ok(())
+}
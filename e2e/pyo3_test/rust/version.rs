@@ -0,0 +1,404 @@
+use std::cmp::Ordering;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// A parsed PEP 440 version, comparable the same way `packaging.version.Version` is.
+#[pyclass(module = "fromager._lib.version")]
+#[derive(Clone, Debug, Eq)]
+pub struct Version {
+    epoch: u64,
+    release: Vec<u64>,
+    pre: Option<(PreTag, u64)>,
+    post: Option<u64>,
+    dev: Option<u64>,
+    local: Vec<LocalSegment>,
+    original: String,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+enum PreTag {
+    A,
+    B,
+    Rc,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum LocalSegment {
+    Numeric(u64),
+    Alpha(String),
+}
+
+impl Ord for LocalSegment {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use LocalSegment::*;
+        match (self, other) {
+            (Numeric(a), Numeric(b)) => a.cmp(b),
+            (Alpha(a), Alpha(b)) => a.cmp(b),
+            // Numeric segments always outrank alphabetic ones, per PEP 440.
+            (Numeric(_), Alpha(_)) => Ordering::Greater,
+            (Alpha(_), Numeric(_)) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for LocalSegment {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Where a bare (pre-less) release sorts relative to actual pre-release tags:
+/// a dev-only release (no pre, no post) sorts *before* any pre-release of the
+/// same release tuple, while a final/post release sorts *after* all of them.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+enum PreKey {
+    DevOnly,
+    Pre(PreTag, u64),
+    NoPre,
+}
+
+/// `None` (no post-release) sorts before any actual post-release number.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+enum PostKey {
+    NoPost,
+    Post(u64),
+}
+
+/// A dev-release number sorts before the absence of one, so that e.g.
+/// `1.0a1.dev1 < 1.0a1`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+enum DevKey {
+    Dev(u64),
+    NoDev,
+}
+
+fn normalize_pre_tag(tag: &str) -> Option<PreTag> {
+    match tag {
+        "a" | "alpha" => Some(PreTag::A),
+        "b" | "beta" => Some(PreTag::B),
+        "rc" | "c" | "pre" | "preview" => Some(PreTag::Rc),
+        _ => None,
+    }
+}
+
+fn parse_local(segment: &str) -> Vec<LocalSegment> {
+    segment
+        .split(|c| c == '.' || c == '-' || c == '_')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            if let Ok(n) = s.parse::<u64>() {
+                LocalSegment::Numeric(n)
+            } else {
+                LocalSegment::Alpha(s.to_ascii_lowercase())
+            }
+        })
+        .collect()
+}
+
+/// Parse a PEP 440 version string into its component parts.
+///
+/// Grammar (simplified): `[N!]N(.N)*[{a|b|rc}N][.postN|-N][.devN][+local]`.
+fn parse_version(text: &str) -> Result<Version, String> {
+    let normalized = text.trim().to_ascii_lowercase();
+    let normalized = normalized.strip_prefix('v').unwrap_or(&normalized);
+
+    let (main, local) = match normalized.split_once('+') {
+        Some((main, local)) => (main, parse_local(local)),
+        None => (normalized, Vec::new()),
+    };
+
+    let mut rest = main;
+
+    let epoch = if let Some(idx) = rest.find('!') {
+        let epoch_str = &rest[..idx];
+        let epoch = epoch_str
+            .parse::<u64>()
+            .map_err(|_| format!("invalid epoch in version {text:?}"))?;
+        rest = &rest[idx + 1..];
+        epoch
+    } else {
+        0
+    };
+
+    let release_end = rest
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(rest.len());
+    // `rest[..release_end]` may carry a trailing separator (e.g. "1.0." before
+    // "post1"/"dev1"); that dot belongs to the next segment, not the release.
+    let release_str = rest[..release_end].trim_end_matches('.');
+    if release_str.is_empty() {
+        return Err(format!("missing release segment in version {text:?}"));
+    }
+    let release = release_str
+        .split('.')
+        .map(|part| part.parse::<u64>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| format!("invalid release segment in version {text:?}"))?;
+    rest = &rest[release_end..];
+
+    let mut pre = None;
+    if let Some(stripped) = rest.strip_prefix('.').or(Some(rest)) {
+        rest = stripped;
+    }
+    let pre_start = rest.find(|c: char| c.is_ascii_alphabetic());
+    if let Some(start) = pre_start {
+        // Only treat this as a pre-release tag if it appears before any
+        // `.post`/`.dev`/`.rev`/`.r` marker, i.e. it's the first alphabetic
+        // run. `rc` is excluded from the `r`-prefix check since it's itself a
+        // valid pre-release tag.
+        let tail = &rest[start..];
+        let looks_like_post_alias =
+            tail.starts_with("rev") || (tail.starts_with('r') && !tail.starts_with("rc"));
+        if !tail.starts_with("post") && !tail.starts_with("dev") && !looks_like_post_alias {
+            let tag_end = rest[start..]
+                .find(|c: char| c.is_ascii_digit())
+                .map(|i| start + i)
+                .unwrap_or(rest.len());
+            let tag = &rest[start..tag_end];
+            let pre_tag = normalize_pre_tag(tag)
+                .ok_or_else(|| format!("invalid pre-release tag {tag:?} in version {text:?}"))?;
+            let num_end = rest[tag_end..]
+                .find(|c: char| !c.is_ascii_digit())
+                .map(|i| tag_end + i)
+                .unwrap_or(rest.len());
+            let num_str = &rest[tag_end..num_end];
+            let num = if num_str.is_empty() {
+                0
+            } else {
+                num_str
+                    .parse::<u64>()
+                    .map_err(|_| format!("invalid pre-release number in version {text:?}"))?
+            };
+            pre = Some((pre_tag, num));
+            rest = &rest[num_end..];
+        }
+    }
+
+    let mut post = None;
+    if let Some(idx) = rest.find("post") {
+        let after = &rest[idx + 4..];
+        let num_end = after
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(after.len());
+        let num_str = &after[..num_end];
+        let num = if num_str.is_empty() {
+            0
+        } else {
+            num_str
+                .parse::<u64>()
+                .map_err(|_| format!("invalid post-release number in version {text:?}"))?
+        };
+        post = Some(num);
+        rest = &rest[idx + 4 + num_end..];
+    } else if let Some(stripped) = rest.strip_prefix('-') {
+        // Implicit post release, e.g. "1.0-1" means "1.0.post1".
+        let num_end = stripped
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(stripped.len());
+        let num_str = &stripped[..num_end];
+        if !num_str.is_empty() {
+            post = Some(
+                num_str
+                    .parse::<u64>()
+                    .map_err(|_| format!("invalid implicit post-release in version {text:?}"))?,
+            );
+            rest = &stripped[num_end..];
+        }
+    } else if let Some(idx) = rest.find('r') {
+        // `rev`/`r` are accepted aliases for `post`.
+        let after = if rest[idx..].starts_with("rev") {
+            &rest[idx + 3..]
+        } else {
+            &rest[idx + 1..]
+        };
+        let num_end = after
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(after.len());
+        let num_str = &after[..num_end];
+        let num = if num_str.is_empty() {
+            0
+        } else {
+            num_str
+                .parse::<u64>()
+                .map_err(|_| format!("invalid post-release number in version {text:?}"))?
+        };
+        post = Some(num);
+        rest = &after[num_end..];
+    }
+
+    let mut dev = None;
+    if let Some(idx) = rest.find("dev") {
+        let after = &rest[idx + 3..];
+        let num_end = after
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(after.len());
+        let num_str = &after[..num_end];
+        let num = if num_str.is_empty() {
+            0
+        } else {
+            num_str
+                .parse::<u64>()
+                .map_err(|_| format!("invalid dev-release number in version {text:?}"))?
+        };
+        dev = Some(num);
+        rest = &after[num_end..];
+    }
+
+    let remainder: String = rest.chars().filter(|c| c.is_alphanumeric()).collect();
+    if !remainder.is_empty() {
+        return Err(format!("unexpected trailing data in version {text:?}"));
+    }
+
+    Ok(Version {
+        epoch,
+        release,
+        pre,
+        post,
+        dev,
+        local,
+        original: text.to_string(),
+    })
+}
+
+impl Version {
+    fn padded_release(&self, len: usize) -> Vec<u64> {
+        let mut release = self.release.clone();
+        release.resize(len, 0);
+        release
+    }
+
+    fn pre_key(&self) -> PreKey {
+        match (self.pre, self.post, self.dev) {
+            (None, None, Some(_)) => PreKey::DevOnly,
+            (None, _, _) => PreKey::NoPre,
+            (Some((tag, num)), _, _) => PreKey::Pre(tag, num),
+        }
+    }
+
+    fn post_key(&self) -> PostKey {
+        match self.post {
+            None => PostKey::NoPost,
+            Some(num) => PostKey::Post(num),
+        }
+    }
+
+    fn dev_key(&self) -> DevKey {
+        match self.dev {
+            Some(num) => DevKey::Dev(num),
+            None => DevKey::NoDev,
+        }
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // PEP 440 key order: epoch, release, pre, post, dev, local.
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| {
+                let len = self.release.len().max(other.release.len());
+                self.padded_release(len).cmp(&other.padded_release(len))
+            })
+            .then_with(|| self.pre_key().cmp(&other.pre_key()))
+            .then_with(|| self.post_key().cmp(&other.post_key()))
+            .then_with(|| self.dev_key().cmp(&other.dev_key()))
+            .then_with(|| self.local.cmp(&other.local))
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Version {
+    // Equality must agree with `cmp`/`hash`, both of which ignore `original`
+    // (so e.g. "1.0" == "1.0.0"); deriving `PartialEq` would compare it too.
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl std::hash::Hash for Version {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.epoch.hash(state);
+        // `cmp` zero-pads releases, so trailing zeros must not affect the hash.
+        let mut release: &[u64] = &self.release;
+        while let Some((&0, head)) = release.split_last() {
+            release = head;
+        }
+        release.hash(state);
+        self.pre.hash(state);
+        self.post.hash(state);
+        self.dev.hash(state);
+        self.local.hash(state);
+    }
+}
+
+impl std::hash::Hash for LocalSegment {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            LocalSegment::Numeric(n) => n.hash(state),
+            LocalSegment::Alpha(s) => s.hash(state),
+        }
+    }
+}
+
+#[pymethods]
+impl Version {
+    #[staticmethod]
+    fn parse(text: &str) -> PyResult<Self> {
+        parse_version(text).map_err(PyValueError::new_err)
+    }
+
+    #[getter]
+    fn is_prerelease(&self) -> bool {
+        self.pre.is_some() || self.dev.is_some()
+    }
+
+    #[getter]
+    fn is_devrelease(&self) -> bool {
+        self.dev.is_some()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Version({:?})", self.original)
+    }
+
+    fn __str__(&self) -> String {
+        self.original.clone()
+    }
+
+    fn __richcmp__(&self, other: &Self, op: pyo3::basic::CompareOp) -> bool {
+        use pyo3::basic::CompareOp::*;
+        match op {
+            Lt => self < other,
+            Le => self <= other,
+            Eq => self == other,
+            Ne => self != other,
+            Gt => self > other,
+            Ge => self >= other,
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Parse `text` as a PEP 440 version for comparison purposes, or `None` if it
+/// isn't one (e.g. a marker variable like `sys_platform` that isn't version-shaped).
+pub(crate) fn try_parse(text: &str) -> Option<Version> {
+    parse_version(text).ok()
+}
+
+pub fn register(py: Python<'_>, parent: &PyModule) -> PyResult<()> {
+    let _ = py;
+    parent.add_class::<Version>()?;
+    Ok(())
+}
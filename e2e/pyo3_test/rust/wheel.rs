@@ -0,0 +1,235 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::collections::HashSet;
+
+/// The parsed components of a wheel filename, per the binary distribution spec:
+/// `{distribution}-{version}(-{build tag})?-{python tag}-{abi tag}-{platform tag}.whl`.
+#[pyclass(module = "fromager._lib.wheel")]
+#[derive(Clone, Debug)]
+pub struct WheelTag {
+    #[pyo3(get)]
+    distribution: String,
+    #[pyo3(get)]
+    version: String,
+    #[pyo3(get)]
+    build_tag: Option<String>,
+    #[pyo3(get)]
+    python_tag: String,
+    #[pyo3(get)]
+    abi_tag: String,
+    #[pyo3(get)]
+    platform_tag: String,
+}
+
+#[pymethods]
+impl WheelTag {
+    fn __repr__(&self) -> String {
+        format!(
+            "WheelTag(distribution={:?}, version={:?}, python_tag={:?}, abi_tag={:?}, platform_tag={:?})",
+            self.distribution, self.version, self.python_tag, self.abi_tag, self.platform_tag
+        )
+    }
+}
+
+/// Split a wheel filename into its component tags.
+#[pyfunction]
+pub fn parse_wheel_filename(name: &str) -> PyResult<WheelTag> {
+    let stem = name
+        .strip_suffix(".whl")
+        .ok_or_else(|| PyValueError::new_err(format!("not a wheel filename: {name:?}")))?;
+    let parts: Vec<&str> = stem.split('-').collect();
+    if parts.len() != 5 && parts.len() != 6 {
+        return Err(PyValueError::new_err(format!(
+            "malformed wheel filename {name:?}: expected 5 or 6 '-'-separated components"
+        )));
+    }
+    let (build_tag, rest) = if parts.len() == 6 {
+        (Some(parts[2].to_string()), &parts[3..])
+    } else {
+        (None, &parts[2..])
+    };
+    Ok(WheelTag {
+        distribution: parts[0].to_string(),
+        version: parts[1].to_string(),
+        build_tag,
+        python_tag: rest[0].to_string(),
+        abi_tag: rest[1].to_string(),
+        platform_tag: rest[2].to_string(),
+    })
+}
+
+/// Platform aliases that should be treated as compatible with the given platforms,
+/// in the manylinux/musllinux "lowest common denominator" ordering.
+fn expand_platform_aliases(platforms: &[String]) -> Vec<String> {
+    let mut expanded = Vec::new();
+    for platform in platforms {
+        expanded.push(platform.clone());
+        if let Some(rest) = platform.strip_prefix("manylinux_") {
+            // manylinux_2_<glibc minor>_<arch> implies every older
+            // manylinux_2_<N>_<arch> alias down to manylinux_2_0, plus each legacy
+            // alias its glibc covers: manylinux2014 (2.17), manylinux2010 (2.12)
+            // and manylinux1 (2.5). Other glibc majors get no extra aliases.
+            let mut fields = rest.splitn(3, '_');
+            if let (Some(major), Some(minor), Some(arch)) =
+                (fields.next(), fields.next(), fields.next())
+            {
+                if let (Ok(major), Ok(minor)) = (major.parse::<u32>(), minor.parse::<u32>()) {
+                    if major == 2 {
+                        let mut m = minor;
+                        loop {
+                            expanded.push(format!("manylinux_2_{m}_{arch}"));
+                            if m == 0 {
+                                break;
+                            }
+                            m -= 1;
+                        }
+                        if minor >= 17 {
+                            expanded.push(format!("manylinux2014_{arch}"));
+                        }
+                        if minor >= 12 {
+                            expanded.push(format!("manylinux2010_{arch}"));
+                        }
+                        if minor >= 5 {
+                            expanded.push(format!("manylinux1_{arch}"));
+                        }
+                    }
+                }
+            }
+        } else if let Some(rest) = platform.strip_prefix("musllinux_") {
+            // musllinux_<major>_<minor>_<arch> implies every older
+            // musllinux_<major>_<N>_<arch> alias down to musllinux_<major>_0.
+            let mut fields = rest.splitn(3, '_');
+            if let (Some(major), Some(minor), Some(arch)) =
+                (fields.next(), fields.next(), fields.next())
+            {
+                if let (Ok(major), Ok(minor)) = (major.parse::<u32>(), minor.parse::<u32>()) {
+                    let mut m = minor;
+                    loop {
+                        expanded.push(format!("musllinux_{major}_{m}_{arch}"));
+                        if m == 0 {
+                            break;
+                        }
+                        m -= 1;
+                    }
+                }
+            }
+        }
+    }
+    expanded.push("any".to_string());
+    expanded
+}
+
+/// Generate the ordered, highest-priority-first set of compatible tags for an interpreter.
+///
+/// `python_impl` is e.g. `"cp311"`, `abi` is e.g. `"cp311"`/`"abi3"`/`"none"`, and
+/// `platforms` are the platform tags to accept, most specific first.
+#[pyfunction]
+pub fn compatible_tags(python_impl: &str, abi: &str, platforms: Vec<String>) -> Vec<String> {
+    let mut tags = Vec::new();
+    let mut seen = HashSet::new();
+
+    let expanded_platforms = expand_platform_aliases(&platforms);
+
+    let abis: Vec<String> = if abi == "none" {
+        vec!["none".to_string()]
+    } else {
+        vec![abi.to_string(), "abi3".to_string(), "none".to_string()]
+    };
+
+    // `python_impl` is letters (e.g. "cp") followed by version digits with no
+    // separator (e.g. "311" for 3.11); the first digit is the major version.
+    let digits_start = python_impl
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(python_impl.len());
+    let version_digits = &python_impl[digits_start..];
+    let major = version_digits
+        .chars()
+        .next()
+        .and_then(|c| c.to_digit(10))
+        .unwrap_or(3);
+    let minor: u32 = version_digits
+        .get(1..)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0);
+
+    // `packaging.tags` iterates abi outermost so an exact-abi wheel always
+    // outranks an abi3 wheel, regardless of platform specificity.
+    for candidate_abi in &abis {
+        for platform in &expanded_platforms {
+            let tag = format!("{python_impl}-{candidate_abi}-{platform}");
+            if seen.insert(tag.clone()) {
+                tags.push(tag);
+            }
+        }
+    }
+
+    // Then the generic `py{major}-none-<platform>` universal fallbacks.
+    for platform in expanded_platforms.iter().filter(|p| *p != "any") {
+        let tag = format!("py{major}-none-{platform}");
+        if seen.insert(tag.clone()) {
+            tags.push(tag);
+        }
+    }
+
+    // The full `py{major}{minor}-none-any` series, descending from the
+    // current minor down to 0 (e.g. py311, py310, ..., py30 for CPython 3.11).
+    let mut m = minor;
+    loop {
+        let tag = format!("py{major}{m}-none-any");
+        if seen.insert(tag.clone()) {
+            tags.push(tag);
+        }
+        if m == 0 {
+            break;
+        }
+        m -= 1;
+    }
+
+    // Finally the bare major-only universal tag.
+    let tag = format!("py{major}-none-any");
+    if seen.insert(tag.clone()) {
+        tags.push(tag);
+    }
+
+    tags
+}
+
+/// Return the highest-priority compatible wheel filename, or `None` if none apply.
+#[pyfunction]
+pub fn best_match(
+    py: Python<'_>,
+    filenames: Vec<String>,
+    python_impl: &str,
+    abi: &str,
+    platforms: Vec<String>,
+) -> PyResult<Option<String>> {
+    let _ = py;
+    let priority = compatible_tags(python_impl, abi, platforms);
+
+    let mut best: Option<(usize, String)> = None;
+    for filename in filenames {
+        let parsed = match parse_wheel_filename(&filename) {
+            Ok(parsed) => parsed,
+            Err(_) => continue,
+        };
+        let tag = format!(
+            "{}-{}-{}",
+            parsed.python_tag, parsed.abi_tag, parsed.platform_tag
+        );
+        if let Some(rank) = priority.iter().position(|candidate| candidate == &tag) {
+            if best.as_ref().map_or(true, |(best_rank, _)| rank < *best_rank) {
+                best = Some((rank, filename));
+            }
+        }
+    }
+    Ok(best.map(|(_, filename)| filename))
+}
+
+pub fn register(py: Python<'_>, parent: &PyModule) -> PyResult<()> {
+    let _ = py;
+    parent.add_class::<WheelTag>()?;
+    parent.add_function(wrap_pyfunction!(parse_wheel_filename, parent)?)?;
+    parent.add_function(wrap_pyfunction!(compatible_tags, parent)?)?;
+    parent.add_function(wrap_pyfunction!(best_match, parent)?)?;
+    Ok(())
+}